@@ -0,0 +1,91 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::{fs, path::PathBuf, rc::Rc};
+
+use dioxus::prelude::*;
+use dioxus_redux::prelude::*;
+use serde::{Deserialize, Serialize};
+
+fn main() {
+    dioxus_desktop::launch(app);
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CoolStore {
+    tasks: Vec<String>,
+}
+
+impl CoolStore {
+    fn new() -> Self {
+        Self {
+            tasks: vec!["Todo A".to_string()],
+        }
+    }
+
+    pub fn tasks(&self) -> Vec<String> {
+        self.tasks.clone()
+    }
+}
+
+enum CoolStoreEvent {
+    PushTask(String),
+}
+
+impl Store for CoolStore {
+    type Event = CoolStoreEvent;
+
+    fn handle(&mut self, event: Self::Event) {
+        match event {
+            CoolStoreEvent::PushTask(task) => self.tasks.push(task),
+        }
+    }
+}
+
+// Persists to a plain file on disk; a real app would use platform storage (e.g. localStorage
+// on the web, or a config-dir file on desktop).
+struct FileBackend {
+    path: PathBuf,
+}
+
+impl StorageBackend for FileBackend {
+    fn load(&self) -> Option<Vec<u8>> {
+        fs::read(&self.path).ok()
+    }
+
+    fn save(&self, bytes: &[u8]) {
+        let _ = fs::write(&self.path, bytes);
+    }
+}
+
+fn app(cx: Scope) -> Element {
+    let backend = Rc::new(FileBackend {
+        path: std::env::temp_dir().join("dioxus-redux-example.json"),
+    }) as Rc<dyn StorageBackend>;
+
+    // Rehydrates from the file on startup, and debounces a write back to it after every
+    // dispatch.
+    use_init_store_with_persistence(cx, CoolStore::new, backend);
+
+    let tasks_slice = use_slice(cx, CoolStore::tasks);
+    let dispatcher = use_dispatcher::<CoolStore>(cx);
+
+    let onclick = move |_| dispatcher.dispatch(CoolStoreEvent::PushTask("Hello World".to_string()));
+
+    render!(
+        button {
+            onclick: onclick,
+            "New Task"
+        }
+        ul {
+            for (i, task) in tasks_slice.read().borrow().iter().enumerate() {
+                li {
+                    key: "{i}",
+                    "{task}"
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::thread;
+
+use dioxus::prelude::*;
+use dioxus_redux::prelude::*;
+
+fn main() {
+    dioxus_desktop::launch(app);
+}
+
+#[derive(Clone)]
+struct CoolStore {
+    tasks: Vec<String>,
+}
+
+impl CoolStore {
+    fn new() -> Self {
+        Self {
+            tasks: vec!["Todo A".to_string()],
+        }
+    }
+
+    pub fn tasks(&self) -> Vec<String> {
+        self.tasks.clone()
+    }
+}
+
+enum CoolStoreEvent {
+    PushTask(String),
+}
+
+impl Store for CoolStore {
+    type Event = CoolStoreEvent;
+
+    fn handle(&mut self, event: Self::Event) {
+        match event {
+            CoolStoreEvent::PushTask(task) => self.tasks.push(task),
+        }
+    }
+}
+
+fn app(cx: Scope) -> Element {
+    // Events sent on `tx` flow into the store alongside regular dispatches; e.g. a
+    // websocket task or a timer could hold on to it.
+    let (tx, rx) = async_channel::unbounded::<CoolStoreEvent>();
+    use_init_store_with_source(cx, CoolStore::new, rx);
+
+    let tasks_slice = use_slice(cx, CoolStore::tasks);
+
+    // Spawns a plain OS thread outside the component tree, handing it a `RemoteDispatcher`
+    // obtained from the store -- the pattern `ReduxStore::remote_dispatcher` exists for.
+    cx.use_hook(|| {
+        let store = cx.consume_context::<ReduxStore<CoolStore>>().unwrap();
+        let remote = store.remote_dispatcher();
+        thread::spawn(move || {
+            remote.dispatch(CoolStoreEvent::PushTask("From a background thread".to_string()));
+        });
+
+        // `tx` itself also reaches the same store, via `use_init_store_with_source`.
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let _ = tx.send_blocking(CoolStoreEvent::PushTask("From an external channel".to_string()));
+        });
+    });
+
+    render!(
+        ul {
+            for (i, task) in tasks_slice.read().borrow().iter().enumerate() {
+                li {
+                    key: "{i}",
+                    "{task}"
+                }
+            }
+        }
+    )
+}
@@ -3,6 +3,8 @@
     windows_subsystem = "windows"
 )]
 
+use std::rc::Rc;
+
 use dioxus::prelude::*;
 use dioxus_redux::prelude::*;
 
@@ -13,24 +15,35 @@ fn main() {
 #[derive(Clone)]
 struct CoolStore {
     tasks: Vec<String>,
+    count: i32,
 }
 
 impl CoolStore {
     fn new() -> Self {
         Self {
             tasks: vec!["Todo A".to_string()],
+            count: 0,
         }
     }
 
     pub fn tasks(&self) -> Vec<String> {
         self.tasks.clone()
     }
+
+    pub fn count(&self) -> i32 {
+        self.count
+    }
+
+    pub fn task_at(&self, index: &usize) -> Option<String> {
+        self.tasks.get(*index).cloned()
+    }
 }
 
 #[allow(dead_code)]
 enum CoolStoreEvent {
     PushTask(String),
     PushTasks(Vec<String>),
+    Bump,
 }
 
 impl Store for CoolStore {
@@ -40,22 +53,64 @@ impl Store for CoolStore {
         match event {
             CoolStoreEvent::PushTask(task) => self.tasks.push(task),
             CoolStoreEvent::PushTasks(tasks) => self.tasks.extend(tasks),
+            CoolStoreEvent::Bump => self.count += 1,
         }
     }
 }
 
+// Logs every event on its way in and out, demonstrating the middleware chain.
+struct Logger;
+
+impl Middleware<CoolStore> for Logger {
+    fn handle(&self, event: CoolStoreEvent, next: &mut dyn FnMut(CoolStoreEvent)) {
+        println!("before dispatch");
+        next(event);
+        println!("after dispatch");
+    }
+}
+
 fn app(cx: Scope) -> Element {
-    use_init_store(cx, CoolStore::new);
+    use_init_store_with_middlewares(
+        cx,
+        CoolStore::new,
+        vec![Rc::new(Logger) as Rc<dyn Middleware<CoolStore>>],
+    );
+
     let tasks_slice = use_slice(cx, CoolStore::tasks);
+    let first_task = use_slice_family(cx, CoolStore::task_at, 0usize);
+    let count = use_slice(cx, CoolStore::count);
+    let summary = use_memo_slice::<CoolStore, _, _>(cx, &[tasks_slice, count], |vals| {
+        let tasks: &Vec<String> = vals[0].downcast_ref().unwrap();
+        let count: &i32 = vals[1].downcast_ref().unwrap();
+        format!("{} tasks, bumped {} times", tasks.len(), count)
+    });
     let dispatcher = use_dispatcher::<CoolStore>(cx);
 
     let onclick = move |_| dispatcher.dispatch(CoolStoreEvent::PushTask("Hello World".to_string()));
 
+    // A thunk: a task spawned on the runtime that dispatches `Bump`, then a follow-up `PushTask`.
+    let bump_dispatcher = use_dispatcher::<CoolStore>(cx);
+    let onbump = move |_| {
+        bump_dispatcher.dispatch_async(cx, |dispatcher| async move {
+            dispatcher.dispatch(CoolStoreEvent::Bump);
+            dispatcher.dispatch(CoolStoreEvent::PushTask("From a thunk".to_string()));
+        });
+    };
+
+    let summary_text = summary.read().borrow().clone();
+    let first_task_text = first_task.read().borrow().clone();
+
     render!(
         button {
             onclick: onclick,
             "New Task"
         }
+        button {
+            onclick: onbump,
+            "Bump (async)"
+        }
+        p { "{summary_text}" }
+        p { "first task: {first_task_text:?}" }
         ul {
             for (i, task) in tasks_slice.read().borrow().iter().enumerate() {
                 li {
@@ -0,0 +1,82 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use dioxus::prelude::*;
+use dioxus_redux::prelude::*;
+
+fn main() {
+    dioxus_desktop::launch(app);
+}
+
+#[derive(Clone)]
+struct CoolStore {
+    tasks: Vec<String>,
+}
+
+impl CoolStore {
+    fn new() -> Self {
+        Self {
+            tasks: vec!["Todo A".to_string()],
+        }
+    }
+
+    pub fn tasks(&self) -> Vec<String> {
+        self.tasks.clone()
+    }
+}
+
+enum CoolStoreEvent {
+    PushTask(String),
+}
+
+impl Store for CoolStore {
+    type Event = CoolStoreEvent;
+
+    fn handle(&mut self, event: Self::Event) {
+        match event {
+            CoolStoreEvent::PushTask(task) => self.tasks.push(task),
+        }
+    }
+}
+
+fn app(cx: Scope) -> Element {
+    // Keeps up to 20 past snapshots, letting `use_time_travel` undo/redo through them.
+    use_init_store_with_history(cx, CoolStore::new, 20);
+
+    let tasks_slice = use_slice(cx, CoolStore::tasks);
+    let dispatcher = use_dispatcher::<CoolStore>(cx);
+    let time_travel = use_time_travel::<CoolStore>(cx);
+
+    let onclick = move |_| dispatcher.dispatch(CoolStoreEvent::PushTask("Hello World".to_string()));
+    let onundo = move |_| {
+        time_travel.undo();
+    };
+    let onredo = move |_| {
+        time_travel.redo();
+    };
+
+    render!(
+        button {
+            onclick: onclick,
+            "New Task"
+        }
+        button {
+            onclick: onundo,
+            "Undo"
+        }
+        button {
+            onclick: onredo,
+            "Redo"
+        }
+        ul {
+            for (i, task) in tasks_slice.read().borrow().iter().enumerate() {
+                li {
+                    key: "{i}",
+                    "{task}"
+                }
+            }
+        }
+    )
+}
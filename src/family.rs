@@ -0,0 +1,72 @@
+use std::{any::Any, hash::Hash, marker::PhantomData, rc::Rc};
+
+use dioxus_core::Scope;
+use dioxus_hooks::{to_owned, RefCell};
+
+use crate::{EntryKind, ReduxSlice, ReduxStore, Store, SubscriptionKey};
+
+// Like `use_slice`, but for selectors that take an extra argument, e.g.
+// `use_slice_family(cx, CoolStore::task_at, index)`. Each distinct `arg` gets its own
+// cached value and subscriber set, so subscribing to the same selector with a different
+// argument doesn't clobber or re-render unrelated scopes; e.g. one `li` per list item can
+// subscribe to just its own index.
+pub fn use_slice_family<
+    'a,
+    F: Copy + 'static + Fn(&S, &A) -> T,
+    S: 'static + 'a + Store,
+    A: 'static + Hash + Eq + Clone,
+    T: 'static + Clone + PartialEq,
+>(
+    cx: Scope<'_>,
+    selector: F,
+    arg: A,
+) -> &ReduxSlice<T> {
+    let store = cx.consume_context::<ReduxStore<S>>().unwrap();
+    let subscribe = cx.use_hook({
+        to_owned![store, arg];
+
+        move || {
+            let key = SubscriptionKey::for_selector_with_arg::<F, A>(&arg);
+
+            let gen_value_getter = {
+                to_owned![store, arg];
+                move || {
+                    let store = &store.store.borrow();
+                    selector(store, &arg)
+                }
+            };
+
+            store.subscribe(
+                cx.scope_id(),
+                key,
+                EntryKind::Base,
+                gen_value_getter,
+                || {
+                    to_owned![store, arg];
+                    Rc::new(move |cached: &Rc<RefCell<Box<dyn Any>>>| {
+                        let store = &store.store.borrow();
+                        let current = selector(store, &arg);
+
+                        // Compare cached and the new value
+                        let is_equal = {
+                            let cached = cached.borrow();
+                            let cached = cached.downcast_ref::<T>().unwrap();
+                            cached == &current
+                        };
+
+                        if !is_equal {
+                            // Update the cached value with the new one
+                            *cached.borrow_mut() = Box::new(current);
+                        }
+                        is_equal
+                    })
+                },
+            )
+        }
+    });
+
+    cx.use_hook(|| ReduxSlice {
+        subscribe: Rc::new(subscribe.clone()),
+        _phantom: PhantomData,
+    })
+}
@@ -1,6 +1,9 @@
 use std::{
     any::{Any, TypeId},
+    cell::Cell,
     collections::{HashMap, HashSet},
+    future::Future,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     rc::Rc,
     sync::Arc,
@@ -9,17 +12,135 @@ use std::{
 use dioxus_core::{Scope, ScopeId};
 use dioxus_hooks::{to_owned, RefCell};
 
+mod family;
+mod memo;
+mod middleware;
+mod persist;
+mod source;
+mod time_travel;
+
+use persist::PersistOps;
+use time_travel::HistoryOps;
+
+pub use family::use_slice_family;
+pub use memo::{use_memo_slice, SliceInput};
+pub use middleware::Middleware;
+pub use persist::{use_init_store_with_persistence, StorageBackend};
+pub use source::{use_init_store_with_source, RemoteDispatcher};
+pub use time_travel::{use_init_store_with_history, use_time_travel, TimeTravel};
+
 pub trait Store {
     type Event;
 
     fn handle(&mut self, event: Self::Event);
 }
 
+// Identifies a single cached selector invocation. `function_id` alone is enough for plain
+// selectors (one value per selector function), but selector families need to tell apart
+// invocations of the same function with different arguments, hence `arg_hash`. `arg_hash`
+// is only used to pick a `HashMap` bucket; `arg` (and `arg_eq`, which compares two `arg`s at
+// their real type `A`) is what actually disambiguates two different arguments that happen
+// to collide on `arg_hash` -- e.g. a weak or collision-prone `Hash` impl for a caller's `A`.
+type ArgEntry = (Rc<dyn Any>, fn(&dyn Any, &dyn Any) -> bool);
+
+#[derive(Clone)]
+struct SubscriptionKey {
+    function_id: TypeId,
+    arg_hash: u64,
+    arg: Option<ArgEntry>,
+}
+
+impl PartialEq for SubscriptionKey {
+    fn eq(&self, other: &Self) -> bool {
+        if self.function_id != other.function_id || self.arg_hash != other.arg_hash {
+            return false;
+        }
+        match (&self.arg, &other.arg) {
+            (Some((lhs, arg_eq)), Some((rhs, _))) => arg_eq(lhs.as_ref(), rhs.as_ref()),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SubscriptionKey {}
+
+impl Hash for SubscriptionKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.function_id.hash(state);
+        self.arg_hash.hash(state);
+    }
+}
+
+impl SubscriptionKey {
+    fn for_selector<F: 'static>() -> Self {
+        Self {
+            function_id: TypeId::of::<F>(),
+            arg_hash: 0,
+            arg: None,
+        }
+    }
+
+    fn for_selector_with_arg<F: 'static, A: 'static + Hash + Eq + Clone>(arg: &A) -> Self {
+        Self {
+            function_id: TypeId::of::<F>(),
+            arg_hash: hash_arg(arg),
+            arg: Some((Rc::new(arg.clone()) as Rc<dyn Any>, arg_eq::<A>)),
+        }
+    }
+}
+
+fn hash_arg<A: Hash>(arg: &A) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    arg.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn arg_eq<A: 'static + PartialEq>(lhs: &dyn Any, rhs: &dyn Any) -> bool {
+    match (lhs.downcast_ref::<A>(), rhs.downcast_ref::<A>()) {
+        (Some(lhs), Some(rhs)) => lhs == rhs,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Selector;
+
+    // Hashes to the same bucket no matter the value, so `arg_hash` alone can't tell two
+    // `CollidingArg`s apart -- only `arg`/`arg_eq` can.
+    #[derive(Clone, PartialEq, Eq)]
+    struct CollidingArg(u32);
+
+    impl Hash for CollidingArg {
+        fn hash<H: Hasher>(&self, _state: &mut H) {}
+    }
+
+    #[test]
+    fn distinct_args_that_collide_on_arg_hash_get_distinct_keys() {
+        let a = SubscriptionKey::for_selector_with_arg::<Selector, _>(&CollidingArg(1));
+        let b = SubscriptionKey::for_selector_with_arg::<Selector, _>(&CollidingArg(2));
+
+        assert_eq!(a.arg_hash, b.arg_hash);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn same_arg_produces_equal_keys() {
+        let a = SubscriptionKey::for_selector_with_arg::<Selector, _>(&CollidingArg(1));
+        let b = SubscriptionKey::for_selector_with_arg::<Selector, _>(&CollidingArg(1));
+
+        assert!(a == b);
+    }
+}
+
 #[derive(Clone)]
 struct Subscription {
     value_entry: ValueEntry,
     subscriptions: Subscriptions,
-    function_id: TypeId,
+    key: SubscriptionKey,
     scope_id: ScopeId,
 }
 
@@ -28,7 +149,7 @@ impl Drop for Subscription {
         let mut subscriptions = self.subscriptions.borrow_mut();
 
         let no_more_subscriptions = {
-            let function = subscriptions.get_mut(&self.function_id);
+            let function = subscriptions.get_mut(&self.key);
             if let Some(function) = function {
                 // Unsubscribe this scope
                 function.scopes.borrow_mut().remove(&self.scope_id);
@@ -40,13 +161,22 @@ impl Drop for Subscription {
 
         if no_more_subscriptions {
             // Remove the subscription itself if there are no more subscribers
-            subscriptions.remove(&self.function_id);
+            subscriptions.remove(&self.key);
         }
     }
 }
 
 type ValueComparer = Rc<dyn Fn(&Rc<RefCell<Box<dyn Any>>>) -> bool>;
 
+// Whether an entry is read straight off the store (`Base`) or combines other entries
+// (`Derived`). `ReduxStore::apply` refreshes every `Base` entry before any `Derived` one, so a
+// derived entry always observes up-to-date input versions regardless of `HashMap` iteration order.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Base,
+    Derived,
+}
+
 #[derive(Clone)]
 struct ValueEntry {
     // Scopes subscribed to this value
@@ -55,9 +185,13 @@ struct ValueEntry {
     value: Rc<RefCell<Box<dyn Any>>>,
     // A function to compare the cached and new value
     compare: ValueComparer,
+    // Bumped every time `compare` actually replaces the cached value; lets derived selectors
+    // know whether an input changed without re-running their (possibly expensive) combiner
+    version: Rc<Cell<u64>>,
+    kind: EntryKind,
 }
 
-type Subscriptions = Rc<RefCell<HashMap<TypeId, ValueEntry>>>;
+type Subscriptions = Rc<RefCell<HashMap<SubscriptionKey, ValueEntry>>>;
 
 pub struct ReduxStore<S: Store> {
     // Actual provided store
@@ -68,36 +202,114 @@ pub struct ReduxStore<S: Store> {
     subscriptions: Subscriptions,
 
     schedule_update_any: Arc<dyn Fn(ScopeId)>,
+
+    // Middlewares the event passes through, in registration order, before it reaches `store`
+    middlewares: Rc<Vec<Rc<dyn Middleware<S>>>>,
+
+    // Ring buffer of past snapshots backing `undo`/`redo`/`jump_to`, present only when the
+    // store was initialized with `use_init_store_with_history`
+    history: Option<Rc<HistoryOps<S>>>,
+
+    // Debounced writer to a `StorageBackend`, present only when the store was initialized
+    // with `use_init_store_with_persistence`
+    persist: Option<Rc<PersistOps<S>>>,
 }
 
 impl<S: Store> ReduxStore<S> {
     pub fn handle(&self, event: S::Event) {
+        self.run_middlewares(0, event);
+    }
+
+    // Runs the middleware at `index`, wiring its `next` to continue with `index + 1`,
+    // bottoming out at `apply` once every middleware has had a chance to intercept.
+    fn run_middlewares(&self, index: usize, event: S::Event) {
+        match self.middlewares.get(index) {
+            Some(middleware) => {
+                let middleware = middleware.clone();
+                let mut next = |event: S::Event| self.run_middlewares(index + 1, event);
+                middleware.handle(event, &mut next);
+            }
+            None => self.apply(event),
+        }
+    }
+
+    // Applies the event to the underlying store and marks dirty every scope whose
+    // subscribed value changed as a result
+    fn apply(&self, event: S::Event) {
+        // Record the pre-event state before it's gone, if history is enabled
+        if let Some(history) = &self.history {
+            history.record(&self.store.borrow());
+        }
+
         // Notify the store of the new event
         self.store.borrow_mut().handle(event);
 
-        for (_function, value_entry) in self.subscriptions.borrow().iter() {
-            let cached_value = &value_entry.value;
-            let is_equal = (value_entry.compare)(cached_value);
-            if !is_equal {
-                // Because the cached and new values were not the same this marks as dirty all the scopes subscribed to those values
-                for scope_id in value_entry.scopes.borrow().iter() {
-                    (self.schedule_update_any)(*scope_id)
-                }
+        // Queue the resulting state for a debounced write, if persistence is enabled
+        if let Some(persist) = &self.persist {
+            persist.mark_dirty(&self.store.borrow());
+        }
+
+        self.refresh_all();
+    }
+
+    // Re-runs every entry's comparer against the current store, marking dirty the scopes
+    // of whatever changed. Called after `apply` as well as after `undo`/`redo`/`jump_to`
+    // restore a past snapshot.
+    fn refresh_all(&self) {
+        let subscriptions = self.subscriptions.borrow();
+
+        // Refresh `Base` entries first so that any `Derived` entry below sees up-to-date
+        // input versions no matter what order the `HashMap` happens to iterate in.
+        for value_entry in subscriptions
+            .values()
+            .filter(|entry| entry.kind == EntryKind::Base)
+        {
+            self.refresh(value_entry);
+        }
+
+        // `Derived` entries aren't ordered relative to each other, and one derived selector
+        // can itself be an input to another (e.g. `use_memo_slice` chained off another
+        // `use_memo_slice`), so a single pass could refresh a selector before an upstream
+        // derived entry it depends on has updated. Keep re-running the pass until a full
+        // sweep changes nothing, so dependency chains of any length converge.
+        loop {
+            let mut any_changed = false;
+            for value_entry in subscriptions
+                .values()
+                .filter(|entry| entry.kind == EntryKind::Derived)
+            {
+                any_changed |= self.refresh(value_entry);
+            }
+            if !any_changed {
+                break;
+            }
+        }
+    }
+
+    fn refresh(&self, value_entry: &ValueEntry) -> bool {
+        let is_equal = (value_entry.compare)(&value_entry.value);
+        if !is_equal {
+            value_entry.version.set(value_entry.version.get() + 1);
+            // Because the cached and new values were not the same this marks as dirty all the scopes subscribed to those values
+            for scope_id in value_entry.scopes.borrow().iter() {
+                (self.schedule_update_any)(*scope_id)
             }
         }
+        !is_equal
     }
 
     fn subscribe<V: 'static>(
         &self,
         scope_id: ScopeId,
-        function_id: TypeId,
+        key: SubscriptionKey,
+        kind: EntryKind,
         value: impl FnOnce() -> V,
         compare: impl FnOnce() -> ValueComparer,
     ) -> Subscription {
         let value_entry = {
             let mut subscriptions = self.subscriptions.borrow_mut();
             subscriptions
-                .entry(function_id)
+                .entry(key.clone())
                 .and_modify(|entry| {
                     entry.scopes.borrow_mut().insert(scope_id);
                 })
@@ -105,6 +317,8 @@ impl<S: Store> ReduxStore<S> {
                     scopes: Rc::new(RefCell::new(HashSet::from([scope_id]))),
                     value: Rc::new(RefCell::new(Box::new(value()))),
                     compare: compare(),
+                    version: Rc::new(Cell::new(0)),
+                    kind,
                 })
                 .clone()
         };
@@ -112,7 +326,7 @@ impl<S: Store> ReduxStore<S> {
         Subscription {
             value_entry,
             subscriptions: self.subscriptions.clone(),
-            function_id,
+            key,
             scope_id,
         }
     }
@@ -125,11 +339,39 @@ impl<S: Store> Clone for ReduxStore<S> {
             event_dispatcher: self.event_dispatcher.clone(),
             subscriptions: self.subscriptions.clone(),
             schedule_update_any: self.schedule_update_any.clone(),
+            middlewares: self.middlewares.clone(),
+            history: self.history.clone(),
+            persist: self.persist.clone(),
         }
     }
 }
 
 pub fn use_init_store<S: Store + 'static>(cx: Scope, create_store: impl FnOnce() -> S) {
+    use_init_store_with_middlewares(cx, create_store, Vec::new());
+}
+
+// Like `use_init_store`, but every dispatched event is first folded through `middlewares`,
+// in the given order, before it reaches `Store::handle`.
+pub fn use_init_store_with_middlewares<S: Store + 'static>(
+    cx: Scope,
+    create_store: impl FnOnce() -> S,
+    middlewares: Vec<Rc<dyn Middleware<S>>>,
+) {
+    init_store(cx, create_store, middlewares, None, None, None);
+}
+
+// Shared setup behind every `use_init_store*` variant: creates the event channel, provides
+// the `ReduxStore` context, and spawns the loop that drains dispatched events into it. When
+// `source` is given, a second loop drains it into the same store, so events dispatched from
+// outside the component tree re-render subscribers exactly like a normal dispatch.
+fn init_store<S: Store + 'static>(
+    cx: Scope,
+    create_store: impl FnOnce() -> S,
+    middlewares: Vec<Rc<dyn Middleware<S>>>,
+    history: Option<Rc<HistoryOps<S>>>,
+    persist: Option<Rc<PersistOps<S>>>,
+    source: Option<async_channel::Receiver<S::Event>>,
+) {
     cx.use_hook(|| {
         let (event_tx, event_rx) = async_channel::unbounded::<S::Event>();
 
@@ -138,13 +380,27 @@ pub fn use_init_store<S: Store + 'static>(cx: Scope, create_store: impl FnOnce()
             event_dispatcher: event_tx,
             subscriptions: Rc::default(),
             schedule_update_any: cx.schedule_update_any(),
+            middlewares: Rc::new(middlewares),
+            history,
+            persist,
         });
 
-        cx.spawn(async move {
-            while let Ok(event) = event_rx.recv().await {
-                store.handle(event)
+        cx.spawn({
+            to_owned![store];
+            async move {
+                while let Ok(event) = event_rx.recv().await {
+                    store.handle(event)
+                }
             }
         });
+
+        if let Some(source) = source {
+            cx.spawn(async move {
+                while let Ok(event) = source.recv().await {
+                    store.handle(event)
+                }
+            });
+        }
     });
 }
 
@@ -170,26 +426,32 @@ pub fn use_slice<
                 }
             };
 
-            store.subscribe(cx.scope_id(), TypeId::of::<F>(), gen_value_getter, || {
-                to_owned![store];
-                Rc::new(move |cached: &Rc<RefCell<Box<dyn Any>>>| {
-                    let store = &store.store.borrow();
-                    let current = slicer(store);
-
-                    // Compare cached and the new value
-                    let is_equal = {
-                        let cached = cached.borrow();
-                        let cached = cached.downcast_ref::<T>().unwrap();
-                        cached == &current
-                    };
-
-                    if !is_equal {
-                        // Update the cached value with the new one
-                        *cached.borrow_mut() = Box::new(current);
-                    }
-                    is_equal
-                })
-            })
+            store.subscribe(
+                cx.scope_id(),
+                SubscriptionKey::for_selector::<F>(),
+                EntryKind::Base,
+                gen_value_getter,
+                || {
+                    to_owned![store];
+                    Rc::new(move |cached: &Rc<RefCell<Box<dyn Any>>>| {
+                        let store = &store.store.borrow();
+                        let current = slicer(store);
+
+                        // Compare cached and the new value
+                        let is_equal = {
+                            let cached = cached.borrow();
+                            let cached = cached.downcast_ref::<T>().unwrap();
+                            cached == &current
+                        };
+
+                        if !is_equal {
+                            // Update the cached value with the new one
+                            *cached.borrow_mut() = Box::new(current);
+                        }
+                        is_equal
+                    })
+                },
+            )
         }
     });
 
@@ -216,17 +478,37 @@ fn downcast<T: Any>(v: Rc<RefCell<Box<dyn Any>>>) -> Rc<RefCell<Box<T>>> {
     unsafe { Rc::from_raw(v as *const RefCell<Box<T>>) }
 }
 
-#[derive(Clone)]
 pub struct ReduxDispatcher<S: Store> {
     // Dispatch events
     event_dispatcher: async_channel::Sender<S::Event>,
 }
 
+impl<S: Store> Clone for ReduxDispatcher<S> {
+    fn clone(&self) -> Self {
+        Self {
+            event_dispatcher: self.event_dispatcher.clone(),
+        }
+    }
+}
+
 impl<S: Store> ReduxDispatcher<S> {
     pub fn dispatch(&self, event: S::Event) {
         // TODO: Handle errors
         self.event_dispatcher.try_send(event).unwrap();
     }
+
+    // Spawns `thunk` on the Dioxus runtime, handing it a clone of this dispatcher so it can
+    // await async work and then `dispatch` follow-up events once it's done. The clone keeps
+    // working even after `cx`'s component has re-rendered.
+    pub fn dispatch_async<F, Fut>(&self, cx: Scope, thunk: F)
+    where
+        S: 'static,
+        F: FnOnce(ReduxDispatcher<S>) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let dispatcher = self.clone();
+        cx.spawn(thunk(dispatcher));
+    }
 }
 
 pub fn use_dispatcher<S: 'static + Store>(cx: Scope) -> ReduxDispatcher<S> {
@@ -0,0 +1,102 @@
+use std::{any::Any, cell::Cell, marker::PhantomData, rc::Rc};
+
+use dioxus_core::Scope;
+use dioxus_hooks::RefCell;
+
+use crate::{EntryKind, ReduxSlice, ReduxStore, Store, SubscriptionKey, ValueComparer};
+
+// An input's version counter paired with its cached value
+type MemoInput = (Rc<Cell<u64>>, Rc<RefCell<Box<dyn Any>>>);
+
+// A previously-obtained `ReduxSlice`, usable as an input to `use_memo_slice`. Implemented
+// for `ReduxSlice<T>` itself so any slice returned by `use_slice` or `use_slice_family` can
+// be fed into a derived selector.
+pub trait SliceInput {
+    fn version_cell(&self) -> Rc<Cell<u64>>;
+    fn value_cell(&self) -> Rc<RefCell<Box<dyn Any>>>;
+}
+
+impl<T: 'static> SliceInput for ReduxSlice<T> {
+    fn version_cell(&self) -> Rc<Cell<u64>> {
+        self.subscribe.value_entry.version.clone()
+    }
+
+    fn value_cell(&self) -> Rc<RefCell<Box<dyn Any>>> {
+        self.subscribe.value_entry.value.clone()
+    }
+}
+
+// A selector derived from other selectors, recomputed only when at least one of `inputs`
+// changed since the last dispatch. Each input's `ValueEntry` carries a version counter
+// that is bumped whenever its cached value is replaced; `use_memo_slice` remembers the
+// versions it last saw and skips calling `combiner` entirely when none of them advanced.
+pub fn use_memo_slice<
+    'a,
+    S: 'static + 'a + Store,
+    T: 'static + Clone + PartialEq,
+    C: Copy + 'static + Fn(&[&dyn Any]) -> T,
+>(
+    cx: Scope<'a>,
+    inputs: &[&dyn SliceInput],
+    combiner: C,
+) -> &'a ReduxSlice<T> {
+    let store = cx.consume_context::<ReduxStore<S>>().unwrap();
+    let subscribe = cx.use_hook(|| {
+        let inputs: Vec<MemoInput> = inputs
+            .iter()
+            .map(|input| (input.version_cell(), input.value_cell()))
+            .collect();
+
+        let gen_value_getter = {
+            let inputs = inputs.clone();
+            move || combine(&inputs, combiner)
+        };
+
+        let compare: ValueComparer = {
+            let last_versions = RefCell::new(read_versions(&inputs));
+            Rc::new(move |cached: &Rc<RefCell<Box<dyn Any>>>| {
+                let current_versions = read_versions(&inputs);
+                if current_versions == *last_versions.borrow() {
+                    // None of the inputs changed since the last recompute; skip the combiner
+                    return true;
+                }
+                *last_versions.borrow_mut() = current_versions;
+
+                let current = combine(&inputs, combiner);
+                let is_equal = {
+                    let cached = cached.borrow();
+                    let cached = cached.downcast_ref::<T>().unwrap();
+                    cached == &current
+                };
+
+                if !is_equal {
+                    *cached.borrow_mut() = Box::new(current);
+                }
+                is_equal
+            })
+        };
+
+        store.subscribe(
+            cx.scope_id(),
+            SubscriptionKey::for_selector::<C>(),
+            EntryKind::Derived,
+            gen_value_getter,
+            || compare,
+        )
+    });
+
+    cx.use_hook(|| ReduxSlice {
+        subscribe: Rc::new(subscribe.clone()),
+        _phantom: PhantomData,
+    })
+}
+
+fn read_versions(inputs: &[MemoInput]) -> Vec<u64> {
+    inputs.iter().map(|(version, _)| version.get()).collect()
+}
+
+fn combine<T>(inputs: &[MemoInput], combiner: impl Fn(&[&dyn Any]) -> T) -> T {
+    let borrows: Vec<_> = inputs.iter().map(|(_, value)| value.borrow()).collect();
+    let refs: Vec<&dyn Any> = borrows.iter().map(|value| value.as_ref()).collect();
+    combiner(&refs)
+}
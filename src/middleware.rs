@@ -0,0 +1,8 @@
+use crate::Store;
+
+// A link in the chain of middlewares every dispatched event passes through before it
+// reaches `Store::handle`. Call `next` to continue the chain (before, after, or not at
+// all, to observe the event, observe the result, or swallow it).
+pub trait Middleware<S: Store> {
+    fn handle(&self, event: S::Event, next: &mut dyn FnMut(S::Event));
+}
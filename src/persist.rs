@@ -0,0 +1,150 @@
+use std::{rc::Rc, time::Duration};
+
+use dioxus_core::Scope;
+use dioxus_hooks::to_owned;
+use futures::{future::FutureExt, select};
+use futures_timer::Delay;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{init_store, Store};
+
+// How long to wait after the last dispatch before writing to storage, so a burst of rapid
+// events coalesces into a single write instead of one per event.
+const PERSIST_DEBOUNCE: Duration = Duration::from_millis(500);
+
+// A place to read/write the store's serialized bytes, e.g. a file on desktop or
+// localStorage on the web.
+pub trait StorageBackend {
+    fn load(&self) -> Option<Vec<u8>>;
+    fn save(&self, bytes: &[u8]);
+}
+
+type Serializer<S> = Box<dyn Fn(&S) -> Option<Vec<u8>>>;
+
+// Type-erased handle for persisting `S`, built only by `use_init_store_with_persistence`
+// where `S: Serialize` is known; `ReduxStore` holds it without requiring that bound itself,
+// the same trick `HistoryOps` uses to stay generic over `S`.
+pub(crate) struct PersistOps<S> {
+    serialize: Serializer<S>,
+    dirty: async_channel::Sender<Vec<u8>>,
+}
+
+impl<S> PersistOps<S> {
+    // Called after every dispatch: serializes `current` and pings the debounced writer
+    // loop spawned by `use_init_store_with_persistence`.
+    pub(crate) fn mark_dirty(&self, current: &S) {
+        if let Some(bytes) = (self.serialize)(current) {
+            let _ = self.dirty.try_send(bytes);
+        }
+    }
+}
+
+// Drains `dirty`, coalescing a burst of pings into a single write: every time a newer
+// payload arrives before `PERSIST_DEBOUNCE` elapses, it replaces the pending one and the
+// wait restarts, so only the latest state right before a quiet period gets saved.
+async fn debounced_writer(
+    backend: Rc<dyn StorageBackend>,
+    dirty: async_channel::Receiver<Vec<u8>>,
+) {
+    let mut pending: Option<Vec<u8>> = None;
+    loop {
+        match pending.take() {
+            Some(bytes) => {
+                let mut delay = Delay::new(PERSIST_DEBOUNCE).fuse();
+                let mut next = dirty.recv().fuse();
+                select! {
+                    _ = delay => backend.save(&bytes),
+                    msg = next => match msg {
+                        Ok(newer) => pending = Some(newer),
+                        Err(_) => {
+                            // The store (and `dirty_tx` with it) was dropped with a write
+                            // still pending -- flush it so the last state before shutdown
+                            // isn't silently lost.
+                            backend.save(&bytes);
+                            break;
+                        }
+                    },
+                }
+            }
+            None => match dirty.recv().await {
+                Ok(bytes) => pending = Some(bytes),
+                Err(_) => break,
+            },
+        }
+    }
+}
+
+// Like `use_init_store`, but rehydrates `S` from `backend` on startup (falling back to
+// `create_store` if nothing was saved, or it failed to deserialize), and after each
+// dispatch debounces a write of the current state back to `backend`. Requires
+// `S: Serialize + DeserializeOwned`.
+pub fn use_init_store_with_persistence<S: Store + Serialize + DeserializeOwned + 'static>(
+    cx: Scope,
+    create_store: impl FnOnce() -> S,
+    backend: Rc<dyn StorageBackend>,
+) {
+    let persist = cx.use_hook({
+        to_owned![backend];
+        move || {
+            let (dirty_tx, dirty_rx) = async_channel::unbounded::<Vec<u8>>();
+            cx.spawn(debounced_writer(backend, dirty_rx));
+            Rc::new(PersistOps {
+                serialize: Box::new(|state: &S| serde_json::to_vec(state).ok()),
+                dirty: dirty_tx,
+            })
+        }
+    });
+
+    init_store(
+        cx,
+        move || {
+            backend
+                .load()
+                .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+                .unwrap_or_else(create_store)
+        },
+        Vec::new(),
+        None,
+        Some(persist.clone()),
+        None,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    struct RecordingBackend {
+        saved: RefCell<Vec<Vec<u8>>>,
+    }
+
+    impl StorageBackend for RecordingBackend {
+        fn load(&self) -> Option<Vec<u8>> {
+            None
+        }
+        fn save(&self, bytes: &[u8]) {
+            self.saved.borrow_mut().push(bytes.to_vec());
+        }
+    }
+
+    #[test]
+    fn flushes_pending_write_when_the_channel_closes() {
+        let backend = Rc::new(RecordingBackend {
+            saved: RefCell::new(Vec::new()),
+        });
+        let (dirty_tx, dirty_rx) = async_channel::unbounded::<Vec<u8>>();
+
+        futures::executor::block_on(async {
+            dirty_tx.send(b"last state".to_vec()).await.unwrap();
+            drop(dirty_tx);
+            debounced_writer(backend.clone(), dirty_rx).await;
+        });
+
+        assert_eq!(
+            backend.saved.borrow().as_slice(),
+            &[b"last state".to_vec()]
+        );
+    }
+}
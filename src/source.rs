@@ -0,0 +1,49 @@
+use dioxus_core::Scope;
+
+use crate::{init_store, ReduxStore, Store};
+
+// A handle for dispatching events into a `ReduxStore` from outside the component tree --
+// another thread, a websocket task, a timer -- obtained via `ReduxStore::remote_dispatcher`.
+// Unlike `ReduxDispatcher`, which `use_dispatcher` builds from a `Scope`, it is built straight
+// from a `ReduxStore`, and is `Send` whenever `E` is, so it can be handed off across thread
+// boundaries.
+pub struct RemoteDispatcher<E> {
+    event_dispatcher: async_channel::Sender<E>,
+}
+
+impl<E> Clone for RemoteDispatcher<E> {
+    fn clone(&self) -> Self {
+        Self {
+            event_dispatcher: self.event_dispatcher.clone(),
+        }
+    }
+}
+
+impl<E> RemoteDispatcher<E> {
+    pub fn dispatch(&self, event: E) {
+        // TODO: Handle errors
+        self.event_dispatcher.try_send(event).unwrap();
+    }
+}
+
+impl<S: Store> ReduxStore<S> {
+    // Returns a `Send`-capable handle for dispatching events from outside the component
+    // tree, e.g. a background thread or a websocket task.
+    pub fn remote_dispatcher(&self) -> RemoteDispatcher<S::Event> {
+        RemoteDispatcher {
+            event_dispatcher: self.event_dispatcher.clone(),
+        }
+    }
+}
+
+// Like `use_init_store`, but also drains `source` into the store via a second spawned loop,
+// so events pushed from outside the component tree -- e.g. through a
+// `ReduxStore::remote_dispatcher` handed off to a background task -- re-render every
+// subscribed slice just like a normal dispatch.
+pub fn use_init_store_with_source<S: Store + 'static>(
+    cx: Scope,
+    create_store: impl FnOnce() -> S,
+    source: async_channel::Receiver<S::Event>,
+) {
+    init_store(cx, create_store, Vec::new(), None, None, Some(source));
+}
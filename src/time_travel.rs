@@ -0,0 +1,269 @@
+use std::{collections::VecDeque, rc::Rc};
+
+use dioxus_core::Scope;
+use dioxus_hooks::RefCell;
+
+use crate::{init_store, ReduxStore, Store};
+
+// A bounded ring buffer of past `S` snapshots, letting a time-travel-enabled store walk
+// backward and forward through its history.
+struct History<S> {
+    capacity: usize,
+    past: VecDeque<S>,
+    future: Vec<S>,
+}
+
+impl<S: Clone> History<S> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            past: VecDeque::new(),
+            future: Vec::new(),
+        }
+    }
+
+    // Called before a new event is applied: records `current` and drops the redo stack,
+    // since it no longer represents a reachable future from this branch.
+    fn record(&mut self, current: &S) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.past.len() == self.capacity {
+            self.past.pop_front();
+        }
+        self.past.push_back(current.clone());
+        self.future.clear();
+    }
+
+    fn undo(&mut self, current: &S) -> Option<S> {
+        let previous = self.past.pop_back()?;
+        self.future.push(current.clone());
+        Some(previous)
+    }
+
+    fn redo(&mut self, current: &S) -> Option<S> {
+        let next = self.future.pop()?;
+        self.past.push_back(current.clone());
+        Some(next)
+    }
+
+    // Walks step by step to the snapshot such that exactly `index` entries remain in
+    // `past` afterwards (`0` is the oldest snapshot still in the ring buffer), returning
+    // the final restored state, or `None` if `index` is out of range.
+    fn jump_to(&mut self, mut current: S, index: usize) -> Option<S> {
+        if index > self.past.len() + self.future.len() {
+            return None;
+        }
+        while self.past.len() > index {
+            current = self.undo(&current).unwrap();
+        }
+        while self.past.len() < index {
+            current = self.redo(&current).unwrap();
+        }
+        Some(current)
+    }
+}
+
+type Restore<S> = Box<dyn Fn(&S) -> Option<S>>;
+type JumpTo<S> = Box<dyn Fn(&S, usize) -> Option<S>>;
+
+// Type-erased handle to a `History<S>`, storing its operations as `dyn Fn` so `ReduxStore`
+// can hold one without itself requiring `S: Clone` -- the same trick `ValueEntry::compare`
+// uses to stay generic over `T`. Only `use_init_store_with_history` builds one, where
+// `S: Clone` is known.
+pub(crate) struct HistoryOps<S> {
+    record: Box<dyn Fn(&S)>,
+    undo: Restore<S>,
+    redo: Restore<S>,
+    jump_to: JumpTo<S>,
+}
+
+impl<S: Clone + 'static> HistoryOps<S> {
+    fn new(capacity: usize) -> Self {
+        let history = Rc::new(RefCell::new(History::<S>::new(capacity)));
+
+        Self {
+            record: {
+                let history = history.clone();
+                Box::new(move |current: &S| history.borrow_mut().record(current))
+            },
+            undo: {
+                let history = history.clone();
+                Box::new(move |current: &S| history.borrow_mut().undo(current))
+            },
+            redo: {
+                let history = history.clone();
+                Box::new(move |current: &S| history.borrow_mut().redo(current))
+            },
+            jump_to: {
+                let history = history.clone();
+                Box::new(move |current: &S, index: usize| {
+                    history.borrow_mut().jump_to(current.clone(), index)
+                })
+            },
+        }
+    }
+}
+
+impl<S> HistoryOps<S> {
+    pub(crate) fn record(&self, current: &S) {
+        (self.record)(current)
+    }
+
+    fn undo(&self, current: &S) -> Option<S> {
+        (self.undo)(current)
+    }
+
+    fn redo(&self, current: &S) -> Option<S> {
+        (self.redo)(current)
+    }
+
+    fn jump_to(&self, current: &S, index: usize) -> Option<S> {
+        (self.jump_to)(current, index)
+    }
+}
+
+impl<S: Store> ReduxStore<S> {
+    // Restores the most recent snapshot recorded before a dispatch, if any, refreshing
+    // every selector exactly as a normal dispatch would. Returns whether there was
+    // anything to undo.
+    pub fn undo(&self) -> bool {
+        self.time_travel(|history, current| history.undo(current))
+    }
+
+    // Re-applies the most recently undone snapshot, if any.
+    pub fn redo(&self) -> bool {
+        self.time_travel(|history, current| history.redo(current))
+    }
+
+    // Jumps directly to the snapshot at `index` in the currently-undoable past (`0` is the
+    // oldest snapshot still in the ring buffer), undoing or redoing through every
+    // intermediate state along the way.
+    pub fn jump_to(&self, index: usize) -> bool {
+        self.time_travel(|history, current| history.jump_to(current, index))
+    }
+
+    fn time_travel(&self, op: impl FnOnce(&HistoryOps<S>, &S) -> Option<S>) -> bool {
+        let history = match &self.history {
+            Some(history) => history,
+            None => return false,
+        };
+
+        let restored = op(history, &self.store.borrow());
+        match restored {
+            Some(restored) => {
+                *self.store.borrow_mut() = restored;
+                self.refresh_all();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// A handle for moving a history-enabled store backward/forward through its recorded
+// snapshots, obtained with `use_time_travel`.
+pub struct TimeTravel<S: Store> {
+    store: ReduxStore<S>,
+}
+
+impl<S: Store> TimeTravel<S> {
+    pub fn undo(&self) -> bool {
+        self.store.undo()
+    }
+
+    pub fn redo(&self) -> bool {
+        self.store.redo()
+    }
+
+    pub fn jump_to(&self, index: usize) -> bool {
+        self.store.jump_to(index)
+    }
+}
+
+pub fn use_time_travel<S: 'static + Store>(cx: Scope) -> TimeTravel<S> {
+    let store = cx.consume_context::<ReduxStore<S>>().unwrap();
+    TimeTravel { store }
+}
+
+// Like `use_init_store`, but keeps a bounded ring buffer of up to `capacity` past `S`
+// snapshots, so `use_time_travel` can undo/redo/jump through them. Requires `S: Clone`
+// since every dispatch snapshots the current state before applying the event.
+pub fn use_init_store_with_history<S: Store + Clone + 'static>(
+    cx: Scope,
+    create_store: impl FnOnce() -> S,
+    capacity: usize,
+) {
+    init_store(
+        cx,
+        create_store,
+        Vec::new(),
+        Some(Rc::new(HistoryOps::new(capacity))),
+        None,
+        None,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity_zero_never_records_so_undo_is_always_a_no_op() {
+        let mut history = History::new(0);
+        history.record(&1);
+        history.record(&2);
+        assert_eq!(history.undo(&3), None);
+    }
+
+    #[test]
+    fn overflow_evicts_the_oldest_snapshot() {
+        let mut history = History::new(2);
+        history.record(&1);
+        history.record(&2);
+        history.record(&3);
+        // Recording with the ring buffer already full evicts `1`, leaving only `2` and `3`.
+        assert_eq!(history.undo(&4), Some(3));
+        assert_eq!(history.undo(&3), Some(2));
+        assert_eq!(history.undo(&2), None);
+    }
+
+    #[test]
+    fn recording_clears_the_redo_stack() {
+        let mut history = History::new(10);
+        history.record(&1);
+        history.record(&2);
+        assert_eq!(history.undo(&3), Some(2));
+        history.record(&3);
+        assert_eq!(history.redo(&3), None);
+    }
+
+    #[test]
+    fn undo_then_redo_round_trips_to_the_same_state() {
+        let mut history = History::new(10);
+        history.record(&1);
+        let previous = history.undo(&2).unwrap();
+        assert_eq!(previous, 1);
+        assert_eq!(history.redo(&previous), Some(2));
+    }
+
+    #[test]
+    fn jump_to_out_of_range_returns_none() {
+        let mut history = History::new(10);
+        history.record(&1);
+        history.record(&2);
+        assert_eq!(history.jump_to(3, 5), None);
+    }
+
+    #[test]
+    fn jump_to_walks_through_every_intermediate_state() {
+        let mut history = History::new(10);
+        history.record(&1);
+        history.record(&2);
+        history.record(&3);
+        // 3 past snapshots recorded (1, 2, 3); jumping to index 0 undoes all the way back.
+        assert_eq!(history.jump_to(4, 0), Some(1));
+        // From there, jumping to index 3 redoes all the way back to where we started.
+        assert_eq!(history.jump_to(1, 3), Some(4));
+    }
+}